@@ -16,6 +16,197 @@ pub enum GoIamError {
 
     #[error("Invalid response: {message}")]
     InvalidResponse { message: String },
+
+    #[error("Permission denied: missing scope(s) {}", missing.join(", "))]
+    PermissionDenied { missing: Vec<String> },
+
+    #[error(
+        "OAuth error: {code}{}{}",
+        error_description.as_ref().map(|d| format!(" - {d}")).unwrap_or_default(),
+        error_uri.as_ref().map(|u| format!(" (See {u})")).unwrap_or_default()
+    )]
+    OAuthError {
+        code: OAuthErrorCode,
+        error_description: Option<String>,
+        error_uri: Option<String>,
+    },
+
+    #[error("Resource not found: {message}")]
+    NotFound { message: String },
+
+    #[error("Conflict: {message}")]
+    Conflict { message: String },
+
+    #[error("Forbidden: {message}")]
+    Forbidden { message: String },
+
+    #[error(
+        "Rate limited{}",
+        retry_after.map(|s| format!(" (retry after {s}s)")).unwrap_or_default()
+    )]
+    RateLimited { retry_after: Option<u64> },
+}
+
+impl GoIamError {
+    /// Classifies an HTTP failure into the most specific variant, reading
+    /// `Retry-After` into [`RateLimited::retry_after`] on a 429.
+    pub fn from_status(status: u16, message: String, headers: &reqwest::header::HeaderMap) -> Self {
+        match status {
+            404 => Self::NotFound { message },
+            409 => Self::Conflict { message },
+            403 => Self::Forbidden { message },
+            429 => {
+                let retry_after = headers
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse().ok());
+                Self::RateLimited { retry_after }
+            }
+            status => Self::ApiError { message, status },
+        }
+    }
+
+    /// Maps this error to the HTTP status code it most closely corresponds
+    /// to, mirroring the classification [`from_status`](Self::from_status)
+    /// uses in reverse. Callers that need to surface a status (e.g. the
+    /// `extract` feature's axum rejection) should use this instead of
+    /// duplicating the match, so the two stay in sync.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::AuthError { .. } => 401,
+            Self::Forbidden { .. } => 403,
+            Self::NotFound { .. } => 404,
+            Self::Conflict { .. } => 409,
+            Self::RateLimited { .. } => 429,
+            Self::ApiError { status, .. } => *status,
+            Self::HttpError(_)
+            | Self::JsonError(_)
+            | Self::InvalidResponse { .. }
+            | Self::PermissionDenied { .. }
+            | Self::OAuthError { .. } => 500,
+        }
+    }
+
+    /// A stable, machine-readable discriminant for this variant, used by
+    /// [`Serialize`](serde::Serialize) so observability tooling can index on
+    /// `kind` without string-matching `Display` output.
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::HttpError(_) => "http_error",
+            Self::JsonError(_) => "json_error",
+            Self::AuthError { .. } => "auth_error",
+            Self::ApiError { .. } => "api_error",
+            Self::InvalidResponse { .. } => "invalid_response",
+            Self::PermissionDenied { .. } => "permission_denied",
+            Self::OAuthError { .. } => "oauth_error",
+            Self::NotFound { .. } => "not_found",
+            Self::Conflict { .. } => "conflict",
+            Self::Forbidden { .. } => "forbidden",
+            Self::RateLimited { .. } => "rate_limited",
+        }
+    }
+}
+
+/// Serializes a [`GoIamError`] as `{ "kind", "message", "status"?, "source"? }`,
+/// recursively walking [`std::error::Error::source`] so the underlying
+/// `reqwest`/`serde_json` cause survives as a nested object for logging/IPC
+/// instead of being flattened into one string.
+impl serde::Serialize for GoIamError {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("GoIamError", 4)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+
+        if let Self::ApiError { status, .. } = self {
+            state.serialize_field("status", status)?;
+        } else {
+            state.skip_field("status")?;
+        }
+
+        match std::error::Error::source(self) {
+            Some(source) => state.serialize_field("source", &ErrorSource(source))?,
+            None => state.skip_field("source")?,
+        }
+
+        state.end()
+    }
+}
+
+/// Recursively serializes an [`std::error::Error`]'s source chain as nested
+/// `{ "message", "source"? }` objects.
+struct ErrorSource<'a>(&'a (dyn std::error::Error + 'static));
+
+impl<'a> serde::Serialize for ErrorSource<'a> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ErrorSource", 2)?;
+        state.serialize_field("message", &self.0.to_string())?;
+        match self.0.source() {
+            Some(source) => state.serialize_field("source", &ErrorSource(source))?,
+            None => state.skip_field("source")?,
+        }
+        state.end()
+    }
+}
+
+/// Machine-readable error codes from an OAuth2 token endpoint, per
+/// [RFC 6749 §5.2](https://www.rfc-editor.org/rfc/rfc6749#section-5.2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OAuthErrorCode {
+    InvalidRequest,
+    InvalidClient,
+    InvalidGrant,
+    UnauthorizedClient,
+    UnsupportedGrantType,
+    InvalidScope,
+    AccessDenied,
+    ServerError,
+    TemporarilyUnavailable,
+    Other(String),
+}
+
+impl OAuthErrorCode {
+    pub fn parse(code: &str) -> Self {
+        match code {
+            "invalid_request" => Self::InvalidRequest,
+            "invalid_client" => Self::InvalidClient,
+            "invalid_grant" => Self::InvalidGrant,
+            "unauthorized_client" => Self::UnauthorizedClient,
+            "unsupported_grant_type" => Self::UnsupportedGrantType,
+            "invalid_scope" => Self::InvalidScope,
+            "access_denied" => Self::AccessDenied,
+            "server_error" => Self::ServerError,
+            "temporarily_unavailable" => Self::TemporarilyUnavailable,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for OAuthErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = match self {
+            Self::InvalidRequest => "invalid_request",
+            Self::InvalidClient => "invalid_client",
+            Self::InvalidGrant => "invalid_grant",
+            Self::UnauthorizedClient => "unauthorized_client",
+            Self::UnsupportedGrantType => "unsupported_grant_type",
+            Self::InvalidScope => "invalid_scope",
+            Self::AccessDenied => "access_denied",
+            Self::ServerError => "server_error",
+            Self::TemporarilyUnavailable => "temporarily_unavailable",
+            Self::Other(code) => code,
+        };
+        write!(f, "{code}")
+    }
 }
 
 pub type Result<T> = std::result::Result<T, GoIamError>;
@@ -47,6 +238,135 @@ mod tests {
             invalid_response.to_string(),
             "Invalid response: Missing data field"
         );
+
+        let permission_denied = GoIamError::PermissionDenied {
+            missing: vec!["write:users".to_string(), "delete:users".to_string()],
+        };
+        assert_eq!(
+            permission_denied.to_string(),
+            "Permission denied: missing scope(s) write:users, delete:users"
+        );
+
+        let oauth_error = GoIamError::OAuthError {
+            code: OAuthErrorCode::InvalidGrant,
+            error_description: Some("the code has expired".to_string()),
+            error_uri: Some("https://example.com/errors/invalid_grant".to_string()),
+        };
+        assert_eq!(
+            oauth_error.to_string(),
+            "OAuth error: invalid_grant - the code has expired (See https://example.com/errors/invalid_grant)"
+        );
+
+        let bare_oauth_error = GoIamError::OAuthError {
+            code: OAuthErrorCode::TemporarilyUnavailable,
+            error_description: None,
+            error_uri: None,
+        };
+        assert_eq!(
+            bare_oauth_error.to_string(),
+            "OAuth error: temporarily_unavailable"
+        );
+    }
+
+    #[test]
+    fn test_status_aware_error_display() {
+        let not_found = GoIamError::NotFound {
+            message: "resource missing".to_string(),
+        };
+        assert_eq!(not_found.to_string(), "Resource not found: resource missing");
+
+        let conflict = GoIamError::Conflict {
+            message: "key already exists".to_string(),
+        };
+        assert_eq!(conflict.to_string(), "Conflict: key already exists");
+
+        let forbidden = GoIamError::Forbidden {
+            message: "missing role".to_string(),
+        };
+        assert_eq!(forbidden.to_string(), "Forbidden: missing role");
+
+        let rate_limited = GoIamError::RateLimited {
+            retry_after: Some(30),
+        };
+        assert_eq!(rate_limited.to_string(), "Rate limited (retry after 30s)");
+
+        let rate_limited_no_header = GoIamError::RateLimited { retry_after: None };
+        assert_eq!(rate_limited_no_header.to_string(), "Rate limited");
+    }
+
+    #[test]
+    fn test_from_status_classifies_known_codes() {
+        let headers = reqwest::header::HeaderMap::new();
+
+        assert!(matches!(
+            GoIamError::from_status(404, "missing".to_string(), &headers),
+            GoIamError::NotFound { .. }
+        ));
+        assert!(matches!(
+            GoIamError::from_status(409, "conflict".to_string(), &headers),
+            GoIamError::Conflict { .. }
+        ));
+        assert!(matches!(
+            GoIamError::from_status(403, "forbidden".to_string(), &headers),
+            GoIamError::Forbidden { .. }
+        ));
+        assert!(matches!(
+            GoIamError::from_status(500, "boom".to_string(), &headers),
+            GoIamError::ApiError { status: 500, .. }
+        ));
+    }
+
+    #[test]
+    fn test_status_code_matches_from_status_classification() {
+        let headers = reqwest::header::HeaderMap::new();
+
+        assert_eq!(
+            GoIamError::from_status(404, "missing".to_string(), &headers).status_code(),
+            404
+        );
+        assert_eq!(
+            GoIamError::from_status(409, "conflict".to_string(), &headers).status_code(),
+            409
+        );
+        assert_eq!(
+            GoIamError::from_status(403, "forbidden".to_string(), &headers).status_code(),
+            403
+        );
+        assert_eq!(
+            GoIamError::from_status(429, "slow down".to_string(), &headers).status_code(),
+            429
+        );
+        assert_eq!(
+            GoIamError::from_status(500, "boom".to_string(), &headers).status_code(),
+            500
+        );
+        assert_eq!(
+            GoIamError::AuthError {
+                message: "bad".to_string()
+            }
+            .status_code(),
+            401
+        );
+    }
+
+    #[test]
+    fn test_from_status_reads_retry_after_header() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "42".parse().unwrap());
+
+        match GoIamError::from_status(429, "slow down".to_string(), &headers) {
+            GoIamError::RateLimited { retry_after } => assert_eq!(retry_after, Some(42)),
+            other => panic!("Expected RateLimited, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_oauth_error_code_parse() {
+        assert_eq!(OAuthErrorCode::parse("invalid_grant"), OAuthErrorCode::InvalidGrant);
+        assert_eq!(
+            OAuthErrorCode::parse("something_new"),
+            OAuthErrorCode::Other("something_new".to_string())
+        );
     }
 
     #[test]
@@ -78,4 +398,54 @@ mod tests {
             _ => panic!("Expected JsonError"),
         }
     }
+
+    #[test]
+    fn test_serialize_includes_kind_and_message() {
+        let err = GoIamError::NotFound {
+            message: "resource missing".to_string(),
+        };
+        let value = serde_json::to_value(&err).expect("failed to serialize error");
+
+        assert_eq!(value["kind"], "not_found");
+        assert_eq!(value["message"], "Resource not found: resource missing");
+        assert!(value.get("source").is_none());
+    }
+
+    #[test]
+    fn test_serialize_api_error_includes_status() {
+        let err = GoIamError::ApiError {
+            message: "boom".to_string(),
+            status: 500,
+        };
+        let value = serde_json::to_value(&err).expect("failed to serialize error");
+
+        assert_eq!(value["kind"], "api_error");
+        assert_eq!(value["status"], 500);
+    }
+
+    #[test]
+    fn test_serialize_json_error_includes_nested_source() {
+        let json_error = serde_json::from_str::<serde_json::Value>("invalid json")
+            .expect_err("Should fail to parse invalid JSON");
+        let err = GoIamError::JsonError(json_error);
+        let value = serde_json::to_value(&err).expect("failed to serialize error");
+
+        assert_eq!(value["kind"], "json_error");
+        let source = value.get("source").expect("expected a nested source");
+        assert!(source["message"].is_string());
+    }
+
+    #[test]
+    fn test_serialize_http_error_includes_nested_source() {
+        let request_error = reqwest::Client::new()
+            .get("not a valid url")
+            .build()
+            .expect_err("expected an invalid URL to fail to build");
+        let err = GoIamError::HttpError(request_error);
+        let value = serde_json::to_value(&err).expect("failed to serialize error");
+
+        assert_eq!(value["kind"], "http_error");
+        let source = value.get("source").expect("expected a nested source");
+        assert!(source["message"].is_string());
+    }
 }
@@ -0,0 +1,175 @@
+//! Axum integration for resolving an authenticated [`User`] from the
+//! `Authorization` header. Gated behind the `extract` feature flag.
+
+use std::sync::Arc;
+
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::{request::Parts, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use crate::error::GoIamError;
+use crate::service::Service;
+use crate::service_impl::ServiceImpl;
+use crate::types::User;
+
+/// An axum extractor that resolves the bearer token in the `Authorization`
+/// header into an authenticated [`User`] via [`Service::me`].
+///
+/// Register an `Arc<ServiceImpl>` in your router state (or a state type
+/// implementing `FromRef<S> for Arc<ServiceImpl>`) and take `AuthUser` as a
+/// handler argument to gate the route.
+pub struct AuthUser(pub User);
+
+/// Rejection returned when the bearer token is missing, malformed, or fails
+/// verification.
+pub enum AuthRejection {
+    MissingHeader,
+    InvalidHeader,
+    Unauthorized(GoIamError),
+}
+
+impl IntoResponse for AuthRejection {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            Self::MissingHeader | Self::InvalidHeader => StatusCode::UNAUTHORIZED,
+            Self::Unauthorized(err) => StatusCode::from_u16(err.status_code())
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+        };
+
+        let message = match self {
+            Self::MissingHeader => "missing Authorization header".to_string(),
+            Self::InvalidHeader => "malformed Authorization header".to_string(),
+            Self::Unauthorized(err) => err.to_string(),
+        };
+
+        (status, message).into_response()
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> FromRequestParts<S> for AuthUser
+where
+    S: Send + Sync,
+    Arc<ServiceImpl>: FromRef<S>,
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .headers
+            .get(axum::http::header::AUTHORIZATION)
+            .ok_or(AuthRejection::MissingHeader)?;
+
+        let header = header.to_str().map_err(|_| AuthRejection::InvalidHeader)?;
+        let token = header
+            .strip_prefix("Bearer ")
+            .ok_or(AuthRejection::InvalidHeader)?;
+
+        let service = Arc::<ServiceImpl>::from_ref(state);
+        let user = service
+            .me(token)
+            .await
+            .map_err(AuthRejection::Unauthorized)?;
+
+        Ok(AuthUser(user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{header::AUTHORIZATION, Request, StatusCode};
+    use mockito::Server;
+
+    fn request_parts(header: Option<&str>) -> Parts {
+        let mut builder = Request::builder().uri("/");
+        if let Some(header) = header {
+            builder = builder.header(AUTHORIZATION, header);
+        }
+        let (parts, _) = builder.body(()).unwrap().into_parts();
+        parts
+    }
+
+    #[tokio::test]
+    async fn test_missing_header_is_rejected() {
+        let state = Arc::new(ServiceImpl::new(
+            "http://localhost".to_string(),
+            "client-id".to_string(),
+            "secret".to_string(),
+        ));
+        let mut parts = request_parts(None);
+
+        let result = AuthUser::from_request_parts(&mut parts, &state).await;
+        assert!(matches!(result, Err(AuthRejection::MissingHeader)));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_header_is_rejected() {
+        let state = Arc::new(ServiceImpl::new(
+            "http://localhost".to_string(),
+            "client-id".to_string(),
+            "secret".to_string(),
+        ));
+        let mut parts = request_parts(Some("Basic not-a-bearer-token"));
+
+        let result = AuthUser::from_request_parts(&mut parts, &state).await;
+        assert!(matches!(result, Err(AuthRejection::InvalidHeader)));
+    }
+
+    #[tokio::test]
+    async fn test_service_failure_maps_to_matching_status() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/me/v1/me")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":false,"message":"not found"}"#)
+            .create_async()
+            .await;
+
+        let state = Arc::new(ServiceImpl::new(
+            server.url(),
+            "client-id".to_string(),
+            "secret".to_string(),
+        ));
+        let mut parts = request_parts(Some("Bearer invalid-token"));
+
+        let result = AuthUser::from_request_parts(&mut parts, &state).await;
+        mock.assert_async().await;
+
+        match result {
+            Err(AuthRejection::Unauthorized(err)) => {
+                let response = AuthRejection::Unauthorized(err).into_response();
+                assert_eq!(response.status(), StatusCode::NOT_FOUND);
+            }
+            Ok(_) => panic!("Expected Unauthorized(NotFound), got Ok"),
+            Err(_) => panic!("Expected Unauthorized(NotFound), got a header rejection"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_success_resolves_auth_user() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/me/v1/me")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"data":{"id":"user-id","project_id":"","name":"Test User","email":"test@example.com","phone":"","enabled":true,"profile_pic":"","expiry":null,"roles":{},"resources":{},"policies":{},"created_at":null,"created_by":"","updated_at":null,"updated_by":""}}"#)
+            .create_async()
+            .await;
+
+        let state = Arc::new(ServiceImpl::new(
+            server.url(),
+            "client-id".to_string(),
+            "secret".to_string(),
+        ));
+        let mut parts = request_parts(Some("Bearer valid-token"));
+
+        let result = AuthUser::from_request_parts(&mut parts, &state).await;
+        mock.assert_async().await;
+
+        let AuthUser(user) = result.expect("expected AuthUser to resolve");
+        assert_eq!(user.id, "user-id");
+        assert_eq!(user.email, "test@example.com");
+    }
+}
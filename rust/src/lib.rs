@@ -32,11 +32,17 @@
 //! ```
 
 pub mod error;
+#[cfg(feature = "extract")]
+pub mod extract;
+pub mod persist;
 pub mod service;
 pub mod service_impl;
+pub mod token;
 pub mod types;
 
-pub use error::{GoIamError, Result};
+pub use error::{GoIamError, OAuthErrorCode, Result};
 pub use service::Service;
-pub use service_impl::{new_service, ServiceImpl};
-pub use types::{Resource, User, UserResource, UserRole};
+pub use persist::{JsonSerializer, Serializer, StoredCredential};
+pub use service_impl::{new_service, ServiceBuilder, ServiceImpl};
+pub use token::{decode_claims, Claims};
+pub use types::{ListResourcesQuery, Page, Resource, Token, User, UserResource, UserRole};
@@ -0,0 +1,178 @@
+//! Pluggable persistence for cached [`User`]/token pairs across restarts.
+//!
+//! Implement [`Serializer`] to swap in a binary or encrypted backend without
+//! changing call sites; [`JsonSerializer`] is the default.
+
+use std::io::{BufRead, BufReader, Read, Write};
+
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GoIamError, Result};
+use crate::types::User;
+
+/// A [`User`] bundled with the access token it was resolved from and, if
+/// known, when that token expires (Unix seconds).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StoredCredential {
+    pub user: User,
+    pub access_token: String,
+    pub expires_at: Option<i64>,
+}
+
+/// Persists and restores [`StoredCredential`]s.
+pub trait Serializer {
+    fn serialize<'a, W, I>(&self, writer: W, credentials: I) -> Result<()>
+    where
+        W: Write,
+        I: Iterator<Item = &'a StoredCredential>;
+
+    fn deserialize<R>(&self, reader: R) -> Result<Vec<StoredCredential>>
+    where
+        R: Read;
+}
+
+/// Default [`Serializer`] that writes one base64-encoded JSON record per
+/// line, so a single corrupted line can be skipped instead of failing the
+/// whole file.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonSerializer;
+
+impl Serializer for JsonSerializer {
+    fn serialize<'a, W, I>(&self, mut writer: W, credentials: I) -> Result<()>
+    where
+        W: Write,
+        I: Iterator<Item = &'a StoredCredential>,
+    {
+        for credential in credentials {
+            let json = serde_json::to_vec(credential)?;
+            let encoded = general_purpose::STANDARD_NO_PAD.encode(json);
+            writeln!(writer, "{encoded}").map_err(|err| GoIamError::InvalidResponse {
+                message: format!("failed to write credential record: {err}"),
+            })?;
+        }
+        Ok(())
+    }
+
+    fn deserialize<R>(&self, reader: R) -> Result<Vec<StoredCredential>>
+    where
+        R: Read,
+    {
+        let mut credentials = Vec::new();
+        let mut saw_record = false;
+        let mut malformed = 0usize;
+
+        for line in BufReader::new(reader).lines() {
+            let line = line.map_err(|err| GoIamError::InvalidResponse {
+                message: format!("failed to read credential record: {err}"),
+            })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            saw_record = true;
+
+            match decode_record(&line) {
+                Ok(credential) => credentials.push(credential),
+                Err(err) => {
+                    malformed += 1;
+                    eprintln!("goiam: skipping corrupted credential record: {err}");
+                }
+            }
+        }
+
+        if saw_record && credentials.is_empty() && malformed > 0 {
+            return Err(GoIamError::InvalidResponse {
+                message: "no valid credential records found".to_string(),
+            });
+        }
+
+        Ok(credentials)
+    }
+}
+
+fn decode_record(line: &str) -> Result<StoredCredential> {
+    let decoded = general_purpose::STANDARD_NO_PAD
+        .decode(line.trim())
+        .map_err(|err| GoIamError::InvalidResponse {
+            message: format!("invalid base64 credential record: {err}"),
+        })?;
+    serde_json::from_slice(&decoded).map_err(GoIamError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::io::Cursor;
+
+    fn sample_credential(id: &str) -> StoredCredential {
+        StoredCredential {
+            user: User {
+                id: id.to_string(),
+                project_id: "proj-1".to_string(),
+                name: "Test User".to_string(),
+                email: "test@example.com".to_string(),
+                phone: String::new(),
+                enabled: true,
+                profile_pic: String::new(),
+                linked_client_id: None,
+                expiry: None,
+                scope: String::new(),
+                roles: HashMap::new(),
+                resources: HashMap::new(),
+                policies: HashMap::new(),
+                created_at: None,
+                created_by: "admin".to_string(),
+                updated_at: None,
+                updated_by: "admin".to_string(),
+            },
+            access_token: "token-value".to_string(),
+            expires_at: Some(1_700_000_000),
+        }
+    }
+
+    #[test]
+    fn test_json_serializer_round_trips_credentials() {
+        let serializer = JsonSerializer;
+        let credentials = vec![sample_credential("user-1"), sample_credential("user-2")];
+
+        let mut buffer = Vec::new();
+        serializer
+            .serialize(&mut buffer, credentials.iter())
+            .expect("serialize should succeed");
+
+        let restored = serializer
+            .deserialize(Cursor::new(buffer))
+            .expect("deserialize should succeed");
+
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored[0].user.id, "user-1");
+        assert_eq!(restored[1].user.id, "user-2");
+    }
+
+    #[test]
+    fn test_json_serializer_skips_corrupted_lines() {
+        let serializer = JsonSerializer;
+        let good = sample_credential("user-1");
+
+        let mut buffer = Vec::new();
+        serializer
+            .serialize(&mut buffer, std::iter::once(&good))
+            .expect("serialize should succeed");
+        buffer.extend_from_slice(b"not-valid-base64!!!\n");
+
+        let restored = serializer
+            .deserialize(Cursor::new(buffer))
+            .expect("a partially corrupted file should still deserialize");
+
+        assert_eq!(restored.len(), 1);
+        assert_eq!(restored[0].user.id, "user-1");
+    }
+
+    #[test]
+    fn test_json_serializer_errors_when_every_record_is_malformed() {
+        let serializer = JsonSerializer;
+        let result = serializer.deserialize(Cursor::new(b"not-valid-base64!!!\n".to_vec()));
+        assert!(result.is_err());
+    }
+}
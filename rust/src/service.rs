@@ -1,5 +1,5 @@
-use crate::error::Result;
-use crate::types::{Resource, User};
+use crate::error::{GoIamError, Result};
+use crate::types::{ListResourcesQuery, Page, Resource, Token, User};
 
 #[async_trait::async_trait]
 pub trait Service: Send + Sync {
@@ -9,9 +9,119 @@ pub trait Service: Send + Sync {
     /// Get current user information
     async fn me(&self, token: &str) -> Result<User>;
 
-    /// Create a new resource
+    /// Acquire a machine-to-machine access token via the client-credentials
+    /// grant, using the service's configured `client_id`/`secret`.
+    async fn get_machine_token(&self) -> Result<Token>;
+
+    /// Create a new resource.
+    ///
+    /// This is a non-idempotent write. With [`RetryConfig`](crate::service_impl::RetryConfig)
+    /// enabled (the default), a request that times out after the server has
+    /// already processed it will be retried and can create a duplicate
+    /// resource.
     async fn create_resource(&self, resource: &Resource, token: &str) -> Result<()>;
 
+    /// Fetch a single resource by ID
+    async fn get_resource(&self, resource_id: &str, token: &str) -> Result<Resource>;
+
+    /// List resources matching `query`, paginated via its cursor
+    async fn list_resources(&self, token: &str, query: ListResourcesQuery) -> Result<Page<Resource>>;
+
+    /// Update an existing resource.
+    ///
+    /// This is a non-idempotent write. With [`RetryConfig`](crate::service_impl::RetryConfig)
+    /// enabled (the default), a request that times out after the server has
+    /// already processed it will be retried and can apply the update twice.
+    async fn update_resource(&self, resource: &Resource, token: &str) -> Result<()>;
+
     /// Delete a resource by ID
     async fn delete_resource(&self, resource_id: &str, token: &str) -> Result<()>;
+
+    /// Resolves `token` to a [`User`] and enforces that it carries every
+    /// scope in `required`, so callers can gate resource operations without
+    /// hand-rolling scope checks at each call site.
+    async fn authorize(&self, token: &str, required: &[&str]) -> Result<User> {
+        let user = self.me(token).await?;
+
+        let missing: Vec<String> = required
+            .iter()
+            .filter(|scope| !user.has_scope(scope))
+            .map(|scope| scope.to_string())
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(GoIamError::PermissionDenied { missing });
+        }
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service_impl::ServiceImpl;
+    use mockito::Server;
+
+    fn me_body(scope: &str) -> String {
+        format!(
+            r#"{{"success":true,"data":{{"id":"user-id","project_id":"","name":"Test User","email":"test@example.com","phone":"","enabled":true,"profile_pic":"","expiry":null,"scope":"{scope}","roles":{{}},"resources":{{}},"policies":{{}},"created_at":null,"created_by":"","updated_at":null,"updated_by":""}}}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_authorize_succeeds_when_all_scopes_present() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/me/v1/me")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(me_body("read:users write:users"))
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let result = service
+            .authorize("valid-token", &["read:users", "write:users"])
+            .await;
+        mock.assert_async().await;
+
+        let user = result.expect("expected authorize to succeed");
+        assert_eq!(user.id, "user-id");
+    }
+
+    #[tokio::test]
+    async fn test_authorize_fails_with_missing_scopes() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/me/v1/me")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(me_body("read:users"))
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let result = service
+            .authorize("valid-token", &["read:users", "delete:users"])
+            .await;
+        mock.assert_async().await;
+
+        match result.unwrap_err() {
+            GoIamError::PermissionDenied { missing } => {
+                assert_eq!(missing, vec!["delete:users".to_string()]);
+            }
+            other => panic!("Expected PermissionDenied, got {other:?}"),
+        }
+    }
 }
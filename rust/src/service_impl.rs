@@ -1,30 +1,235 @@
-use crate::error::{GoIamError, Result};
+use crate::error::{GoIamError, OAuthErrorCode, Result};
 use crate::service::Service;
-use crate::types::{AuthCallbackResponse, Resource, ResourceResponse, User, UserResponse};
+use crate::types::{
+    AuthCallbackResponse, ListResourcesQuery, MachineTokenResponse, Page, Resource,
+    ResourcePageResponse, ResourceResponse, Token, User, UserResponse,
+};
 use base64::{engine::general_purpose, Engine as _};
 use reqwest::Client;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+
+fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Parses an OAuth2 token endpoint's error body (RFC 6749 §5.2), if the
+/// response matches that shape.
+fn oauth_error_from_body(body: &str) -> Option<GoIamError> {
+    let oauth_error: crate::types::OAuthErrorBody = serde_json::from_str(body).ok()?;
+    Some(GoIamError::OAuthError {
+        code: OAuthErrorCode::parse(&oauth_error.error),
+        error_description: oauth_error.error_description,
+        error_uri: oauth_error.error_uri,
+    })
+}
+
+/// Retry policy applied around every HTTP request issued by [`ServiceImpl`].
+///
+/// The default retries 5xx and 429 responses, as well as connection/timeout
+/// errors, with an exponential backoff capped at `max_delay` and jittered to
+/// avoid thundering-herd retries.
+///
+/// Retries are transparent to the caller, including for non-idempotent
+/// writes like `create_resource`/`update_resource`: if the server processed
+/// the request but the response was lost to a timeout, a retry can
+/// double-submit it. Use [`RetryConfig::disabled`] for callers that need
+/// at-most-once semantics on those calls.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub retry_on: Vec<u16>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            retry_on: (500..=599).chain(std::iter::once(429)).collect(),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// A policy that never retries, for latency-sensitive callers.
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+}
+
+fn is_retryable_error(err: &reqwest::Error) -> bool {
+    err.is_timeout() || err.is_connect()
+}
+
+fn retry_after_delay(response: &reqwest::Response) -> Option<Duration> {
+    let status = response.status().as_u16();
+    if status != 429 && status != 503 {
+        return None;
+    }
+    let header = response.headers().get(reqwest::header::RETRY_AFTER)?;
+    let seconds: u64 = header.to_str().ok()?.parse().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+fn jitter(max: Duration) -> Duration {
+    if max.is_zero() {
+        return Duration::ZERO;
+    }
+    let nanos = now_unix_nanos_subsec();
+    Duration::from_millis(nanos % max.as_millis().max(1) as u64)
+}
+
+fn now_unix_nanos_subsec() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| u64::from(d.subsec_nanos()))
+        .unwrap_or(0)
+}
+
+fn backoff_delay(config: &RetryConfig, attempt: u32) -> Duration {
+    let factor = 2u32.checked_pow(attempt).unwrap_or(u32::MAX);
+    let computed = config.base_delay.saturating_mul(factor).min(config.max_delay);
+    computed + jitter(computed / 4)
+}
+
+/// Caches a machine-to-machine [`Token`] behind an [`RwLock`], refreshing it
+/// ahead of `expires_at` by `refresh_skew`.
+struct TokenCache {
+    token: RwLock<Option<Token>>,
+    refresh_skew: Duration,
+}
 
 pub struct ServiceImpl {
     base_url: String,
     client_id: String,
     secret: String,
     client: Client,
+    token_cache: Option<TokenCache>,
+    retry_config: RetryConfig,
 }
 
 impl ServiceImpl {
     pub fn new(base_url: String, client_id: String, secret: String) -> Self {
-        Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
-            client_id,
-            secret,
-            client: Client::new(),
+        ServiceBuilder::new(base_url, client_id, secret)
+            .build()
+            .expect("default ServiceImpl configuration should never fail to build")
+    }
+
+    /// Overrides the retry policy applied to every request. Pass
+    /// [`RetryConfig::disabled`] to turn off retries entirely.
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// Sends requests built by `build`, retrying on a retryable status code
+    /// or connection/timeout error per `self.retry_config`.
+    async fn execute_with_retry<F>(
+        &self,
+        mut build: F,
+    ) -> std::result::Result<reqwest::Response, reqwest::Error>
+    where
+        F: FnMut() -> reqwest::RequestBuilder,
+    {
+        let mut attempt = 0;
+        loop {
+            match build().send().await {
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    if attempt >= self.retry_config.max_retries
+                        || !self.retry_config.retry_on.contains(&status)
+                    {
+                        return Ok(response);
+                    }
+                    let delay = retry_after_delay(&response)
+                        .unwrap_or_else(|| backoff_delay(&self.retry_config, attempt));
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                Err(err) => {
+                    if attempt >= self.retry_config.max_retries || !is_retryable_error(&err) {
+                        return Err(err);
+                    }
+                    attempt += 1;
+                    tokio::time::sleep(backoff_delay(&self.retry_config, attempt - 1)).await;
+                }
+            }
         }
     }
 
+    /// Opts this service into caching the machine-to-machine token acquired
+    /// via [`Service::get_machine_token`], refreshing it `refresh_skew`
+    /// ahead of expiry. Enables
+    /// [`create_resource_with_machine_token`](Self::create_resource_with_machine_token)
+    /// and [`delete_resource_with_machine_token`](Self::delete_resource_with_machine_token)
+    /// so long-running daemons don't have to manage token lifetime themselves.
+    pub fn with_machine_token_caching(mut self, refresh_skew: Duration) -> Self {
+        self.token_cache = Some(TokenCache {
+            token: RwLock::new(None),
+            refresh_skew,
+        });
+        self
+    }
+
     fn basic_auth(&self) -> String {
         let credentials = format!("{}:{}", self.client_id, self.secret);
         format!("Basic {}", general_purpose::STANDARD.encode(credentials))
     }
+
+    /// Returns a valid machine-to-machine access token, refreshing the
+    /// cached one if it is missing or within `refresh_skew` of expiring.
+    async fn machine_token(&self) -> Result<String> {
+        let cache = self
+            .token_cache
+            .as_ref()
+            .ok_or_else(|| GoIamError::InvalidResponse {
+                message: "machine token caching is not enabled; call \
+                          ServiceImpl::with_machine_token_caching first"
+                    .to_string(),
+            })?;
+
+        if let Some(token) = cache.token.read().await.as_ref() {
+            if token.expires_at - now_unix() > cache.refresh_skew.as_secs() as i64 {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let mut guard = cache.token.write().await;
+        if let Some(token) = guard.as_ref() {
+            if token.expires_at - now_unix() > cache.refresh_skew.as_secs() as i64 {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let fresh = self.get_machine_token().await?;
+        let access_token = fresh.access_token.clone();
+        *guard = Some(fresh);
+        Ok(access_token)
+    }
+
+    /// Like [`Service::create_resource`], but acquires the token
+    /// automatically from the cached machine-to-machine credentials.
+    pub async fn create_resource_with_machine_token(&self, resource: &Resource) -> Result<()> {
+        let token = self.machine_token().await?;
+        self.create_resource(resource, &token).await
+    }
+
+    /// Like [`Service::delete_resource`], but acquires the token
+    /// automatically from the cached machine-to-machine credentials.
+    pub async fn delete_resource_with_machine_token(&self, resource_id: &str) -> Result<()> {
+        let token = self.machine_token().await?;
+        self.delete_resource(resource_id, &token).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -33,18 +238,23 @@ impl Service for ServiceImpl {
         let url = format!("{}/auth/v1/verify", self.base_url);
 
         let response = self
-            .client
-            .get(&url)
-            .query(&[("code", code)])
-            .header("Authorization", self.basic_auth())
-            .send()
+            .execute_with_retry(|| {
+                self.client
+                    .get(&url)
+                    .query(&[("code", code)])
+                    .header("Authorization", self.basic_auth())
+            })
             .await?;
 
         if !response.status().is_success() {
-            return Err(GoIamError::ApiError {
-                message: format!("Failed to verify code: {}", response.status()),
-                status: response.status().as_u16(),
-            });
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.text().await.unwrap_or_default();
+            if let Some(oauth_error) = oauth_error_from_body(&body) {
+                return Err(oauth_error);
+            }
+            let message = format!("Failed to verify code: {status}");
+            return Err(GoIamError::from_status(status, message, &headers));
         }
 
         let auth_response: AuthCallbackResponse = response.json().await?;
@@ -69,17 +279,18 @@ impl Service for ServiceImpl {
         let url = format!("{}/me/v1/me", self.base_url);
 
         let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .send()
+            .execute_with_retry(|| {
+                self.client
+                    .get(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+            })
             .await?;
 
         if !response.status().is_success() {
-            return Err(GoIamError::ApiError {
-                message: format!("Failed to fetch user information: {}", response.status()),
-                status: response.status().as_u16(),
-            });
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let message = format!("Failed to fetch user information: {status}");
+            return Err(GoIamError::from_status(status, message, &headers));
         }
 
         let user_response: UserResponse = response.json().await?;
@@ -100,23 +311,68 @@ impl Service for ServiceImpl {
         }
     }
 
+    async fn get_machine_token(&self) -> Result<Token> {
+        let url = format!("{}/auth/v1/token", self.base_url);
+
+        let response = self
+            .execute_with_retry(|| {
+                self.client
+                    .post(&url)
+                    .header("Authorization", self.basic_auth())
+                    .form(&[("grant_type", "client_credentials")])
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.text().await.unwrap_or_default();
+            if let Some(oauth_error) = oauth_error_from_body(&body) {
+                return Err(oauth_error);
+            }
+            let message = format!("Failed to acquire machine token: {status}");
+            return Err(GoIamError::from_status(status, message, &headers));
+        }
+
+        let token_response: MachineTokenResponse = response.json().await?;
+
+        if !token_response.success {
+            return Err(GoIamError::AuthError {
+                message: token_response
+                    .message
+                    .unwrap_or_else(|| "Machine token request failed".to_string()),
+            });
+        }
+
+        match token_response.data {
+            Some(data) => Ok(Token {
+                access_token: data.access_token,
+                expires_at: now_unix() + data.expires_in,
+            }),
+            None => Err(GoIamError::InvalidResponse {
+                message: "No token data received".to_string(),
+            }),
+        }
+    }
+
     async fn create_resource(&self, resource: &Resource, token: &str) -> Result<()> {
         let url = format!("{}/resource/v1/", self.base_url);
 
         let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Content-Type", "application/json")
-            .json(resource)
-            .send()
+            .execute_with_retry(|| {
+                self.client
+                    .post(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .header("Content-Type", "application/json")
+                    .json(resource)
+            })
             .await?;
 
         if !response.status().is_success() {
-            return Err(GoIamError::ApiError {
-                message: format!("Failed to create resource: {}", response.status()),
-                status: response.status().as_u16(),
-            });
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let message = format!("Failed to create resource: {status}");
+            return Err(GoIamError::from_status(status, message, &headers));
         }
 
         let resource_response: ResourceResponse = response.json().await?;
@@ -131,11 +387,258 @@ impl Service for ServiceImpl {
 
         Ok(())
     }
+
+    async fn get_resource(&self, resource_id: &str, token: &str) -> Result<Resource> {
+        let url = format!("{}/resource/v1/{}", self.base_url, resource_id);
+
+        let response = self
+            .execute_with_retry(|| {
+                self.client
+                    .get(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let message = format!("Failed to fetch resource: {status}");
+            return Err(GoIamError::from_status(status, message, &headers));
+        }
+
+        let resource_response: ResourceResponse = response.json().await?;
+
+        if !resource_response.success {
+            return Err(GoIamError::AuthError {
+                message: resource_response
+                    .message
+                    .unwrap_or_else(|| "Resource fetch failed".to_string()),
+            });
+        }
+
+        resource_response
+            .data
+            .ok_or_else(|| GoIamError::InvalidResponse {
+                message: "No resource data received".to_string(),
+            })
+    }
+
+    async fn list_resources(&self, token: &str, query: ListResourcesQuery) -> Result<Page<Resource>> {
+        let url = format!("{}/resource/v1/", self.base_url);
+
+        let mut params: Vec<(&str, String)> = Vec::new();
+        if let Some(key_prefix) = &query.key_prefix {
+            params.push(("key_prefix", key_prefix.clone()));
+        }
+        if let Some(enabled) = query.enabled {
+            params.push(("enabled", enabled.to_string()));
+        }
+        if let Some(limit) = query.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(cursor) = &query.cursor {
+            params.push(("cursor", cursor.clone()));
+        }
+
+        let response = self
+            .execute_with_retry(|| {
+                self.client
+                    .get(&url)
+                    .query(&params)
+                    .header("Authorization", format!("Bearer {}", token))
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let message = format!("Failed to list resources: {status}");
+            return Err(GoIamError::from_status(status, message, &headers));
+        }
+
+        let page_response: ResourcePageResponse = response.json().await?;
+
+        if !page_response.success {
+            return Err(GoIamError::AuthError {
+                message: page_response
+                    .message
+                    .unwrap_or_else(|| "Resource listing failed".to_string()),
+            });
+        }
+
+        page_response
+            .data
+            .ok_or_else(|| GoIamError::InvalidResponse {
+                message: "No resource page received".to_string(),
+            })
+    }
+
+    async fn update_resource(&self, resource: &Resource, token: &str) -> Result<()> {
+        let url = format!("{}/resource/v1/{}", self.base_url, resource.id);
+
+        let response = self
+            .execute_with_retry(|| {
+                self.client
+                    .put(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .header("Content-Type", "application/json")
+                    .json(resource)
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let message = format!("Failed to update resource: {status}");
+            return Err(GoIamError::from_status(status, message, &headers));
+        }
+
+        let resource_response: ResourceResponse = response.json().await?;
+
+        if !resource_response.success {
+            return Err(GoIamError::AuthError {
+                message: resource_response
+                    .message
+                    .unwrap_or_else(|| "Resource update failed".to_string()),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn delete_resource(&self, resource_id: &str, token: &str) -> Result<()> {
+        let url = format!("{}/resource/v1/{}", self.base_url, resource_id);
+
+        let response = self
+            .execute_with_retry(|| {
+                self.client
+                    .delete(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+            })
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let message = format!("Failed to delete resource: {status}");
+            return Err(GoIamError::from_status(status, message, &headers));
+        }
+
+        let resource_response: ResourceResponse = response.json().await?;
+
+        if !resource_response.success {
+            return Err(GoIamError::AuthError {
+                message: resource_response
+                    .message
+                    .unwrap_or_else(|| "Resource deletion failed".to_string()),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds a [`ServiceImpl`] with full control over the underlying
+/// `reqwest::Client` — timeouts, a user agent, default headers, or a
+/// pre-configured connection-pooled client — for callers who need more than
+/// [`new_service`]'s defaults.
+pub struct ServiceBuilder {
+    base_url: String,
+    client_id: String,
+    secret: String,
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    default_headers: reqwest::header::HeaderMap,
+    http_client: Option<Client>,
+    retry_config: RetryConfig,
+}
+
+impl ServiceBuilder {
+    pub fn new(base_url: String, client_id: String, secret: String) -> Self {
+        Self {
+            base_url,
+            client_id,
+            secret,
+            request_timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            default_headers: reqwest::header::HeaderMap::new(),
+            http_client: None,
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    /// Overall timeout for a single request, including connect and body.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for establishing the TCP/TLS connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn default_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Overrides the retry policy on the built service.
+    pub fn retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// Supplies a pre-configured `reqwest::Client`, bypassing
+    /// `request_timeout`/`connect_timeout`/`user_agent`/`default_headers`
+    /// (set those on the supplied client instead).
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    pub fn build(self) -> Result<ServiceImpl> {
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder().default_headers(self.default_headers);
+                if let Some(timeout) = self.request_timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(ServiceImpl {
+            base_url: self.base_url.trim_end_matches('/').to_string(),
+            client_id: self.client_id,
+            secret: self.secret,
+            client,
+            token_cache: None,
+            retry_config: self.retry_config,
+        })
+    }
 }
 
 /// Create a new instance of the Go IAM service
 pub fn new_service(base_url: String, client_id: String, secret: String) -> impl Service {
-    ServiceImpl::new(base_url, client_id, secret)
+    ServiceBuilder::new(base_url, client_id, secret)
+        .build()
+        .expect("default ServiceImpl configuration should never fail to build")
 }
 
 #[cfg(test)]
@@ -190,6 +693,34 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_verify_failure_with_oauth_error_body() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/auth/v1/verify?code=expired-code")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error":"invalid_grant","error_description":"the code has expired"}"#)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let result = service.verify("expired-code").await;
+        mock.assert_async().await;
+
+        match result.unwrap_err() {
+            crate::error::GoIamError::OAuthError { code, .. } => {
+                assert_eq!(code, crate::error::OAuthErrorCode::InvalidGrant);
+            }
+            other => panic!("Expected OAuthError, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_me_success() {
         let mut server = Server::new_async().await;
@@ -288,6 +819,305 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_delete_resource_success() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/resource/v1/resource-1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"message":"Resource deleted successfully"}"#)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let result = service.delete_resource("resource-1", "valid-token").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_resource_failure() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/resource/v1/resource-1")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":false,"message":"Invalid token"}"#)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let result = service.delete_resource("resource-1", "invalid-token").await;
+        mock.assert_async().await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_resource_success() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/resource/v1/resource-1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"data":{"id":"resource-1","name":"Test","description":"","key":"test-key","enabled":true,"project_id":"","created_at":null,"created_by":"","updated_at":null,"updated_by":"","deleted_at":null}}"#)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let result = service.get_resource("resource-1", "valid-token").await;
+        mock.assert_async().await;
+
+        let resource = result.expect("expected a resource");
+        assert_eq!(resource.id, "resource-1");
+    }
+
+    #[tokio::test]
+    async fn test_get_resource_not_found() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/resource/v1/missing")
+            .with_status(404)
+            .with_body(r#"{"success":false,"message":"not found"}"#)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let result = service.get_resource("missing", "valid-token").await;
+        mock.assert_async().await;
+
+        match result.unwrap_err() {
+            crate::error::GoIamError::NotFound { .. } => {}
+            other => panic!("Expected NotFound, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_resources_success() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/resource/v1/?key_prefix=users&limit=10")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"data":{"items":[],"next_cursor":null}}"#)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let query = ListResourcesQuery {
+            key_prefix: Some("users".to_string()),
+            enabled: None,
+            limit: Some(10),
+            cursor: None,
+        };
+        let result = service.list_resources("valid-token", query).await;
+        mock.assert_async().await;
+
+        let page = result.expect("expected a page of resources");
+        assert!(page.items.is_empty());
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_update_resource_success() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/resource/v1/resource-1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"message":"Resource updated successfully"}"#)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let mut resource = Resource::new(
+            "Test Resource".to_string(),
+            "A test resource".to_string(),
+            "test-key".to_string(),
+        );
+        resource.id = "resource-1".to_string();
+
+        let result = service.update_resource(&resource, "valid-token").await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_machine_token_success() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/auth/v1/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"data":{"access_token":"machine-token","expires_in":3600}}"#)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        );
+
+        let result = service.get_machine_token().await;
+        mock.assert_async().await;
+
+        let token = result.expect("expected machine token");
+        assert_eq!(token.access_token, "machine-token");
+        assert!(token.expires_at > now_unix());
+    }
+
+    #[tokio::test]
+    async fn test_machine_token_requires_caching_opt_in() {
+        let service = ServiceImpl::new(
+            "https://example.com".to_string(),
+            "client".to_string(),
+            "secret".to_string(),
+        );
+
+        let result = service.machine_token().await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_resource_with_machine_token_caches_and_reuses() {
+        let mut server = Server::new_async().await;
+        let token_mock = server
+            .mock("POST", "/auth/v1/token")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"data":{"access_token":"machine-token","expires_in":3600}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let resource_mock = server
+            .mock("POST", "/resource/v1/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"message":"Resource created successfully"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        )
+        .with_machine_token_caching(Duration::from_secs(60));
+
+        let resource = Resource::new(
+            "resource-1".to_string(),
+            "Test Resource".to_string(),
+            "test-key".to_string(),
+        );
+
+        service
+            .create_resource_with_machine_token(&resource)
+            .await
+            .expect("first call should succeed");
+        service
+            .create_resource_with_machine_token(&resource)
+            .await
+            .expect("second call should reuse the cached token");
+
+        token_mock.assert_async().await;
+        resource_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_verify_retries_on_503_then_succeeds() {
+        let mut server = Server::new_async().await;
+        let failure_mock = server
+            .mock("GET", "/auth/v1/verify?code=valid-code")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let success_mock = server
+            .mock("GET", "/auth/v1/verify?code=valid-code")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":true,"data":{"access_token":"test-token"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        )
+        .with_retry_config(RetryConfig {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            retry_on: vec![503],
+        });
+
+        let result = service.verify("valid-code").await;
+
+        failure_mock.assert_async().await;
+        success_mock.assert_async().await;
+        assert_eq!(result.unwrap(), "test-token");
+    }
+
+    #[tokio::test]
+    async fn test_verify_does_not_retry_when_disabled() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/auth/v1/verify?code=valid-code")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let service = ServiceImpl::new(
+            server.url(),
+            "test-client-id".to_string(),
+            "test-secret".to_string(),
+        )
+        .with_retry_config(RetryConfig::disabled());
+
+        let result = service.verify("valid-code").await;
+
+        mock.assert_async().await;
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_service_base_url_trimming() {
         let service = ServiceImpl::new(
@@ -300,6 +1130,40 @@ mod tests {
         assert_eq!(service.base_url, "https://example.com");
     }
 
+    #[tokio::test]
+    async fn test_service_builder_defaults_match_new() {
+        let service = ServiceBuilder::new(
+            "https://example.com/".to_string(),
+            "client".to_string(),
+            "secret".to_string(),
+        )
+        .build()
+        .expect("builder with defaults should succeed");
+
+        assert_eq!(service.base_url, "https://example.com");
+        assert_eq!(service.retry_config.max_retries, RetryConfig::default().max_retries);
+    }
+
+    #[tokio::test]
+    async fn test_service_builder_accepts_custom_http_client() {
+        let custom_client = Client::builder()
+            .user_agent("goiam-test")
+            .build()
+            .expect("failed to build custom client");
+
+        let service = ServiceBuilder::new(
+            "https://example.com".to_string(),
+            "client".to_string(),
+            "secret".to_string(),
+        )
+        .http_client(custom_client)
+        .request_timeout(Duration::from_secs(5))
+        .build()
+        .expect("builder with a custom client should succeed");
+
+        assert_eq!(service.base_url, "https://example.com");
+    }
+
     #[tokio::test]
     async fn test_basic_auth_encoding() {
         let service = ServiceImpl::new(
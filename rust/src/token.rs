@@ -0,0 +1,181 @@
+//! JWT access-token inspection.
+//!
+//! The go-iam server issues compact JWS access tokens. [`decode_claims`]
+//! reads the claims locally without round-tripping to `/me/v1/me`; enable
+//! the `jwt-verify` feature to additionally check the signature.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GoIamError, Result};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Claims {
+    pub sub: String,
+    pub exp: i64,
+    pub iat: i64,
+    pub project_id: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Claims {
+    /// Returns `true` if `exp` is at or before the current Unix time.
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        self.exp <= now
+    }
+}
+
+/// Decodes the claims from a compact JWS access token without verifying its
+/// signature.
+pub fn decode_claims(token: &str) -> Result<Claims> {
+    let mut segments = token.split('.');
+    let (Some(_header), Some(payload), Some(_signature)) =
+        (segments.next(), segments.next(), segments.next())
+    else {
+        return Err(GoIamError::InvalidResponse {
+            message: "token is not a valid compact JWS".to_string(),
+        });
+    };
+    if segments.next().is_some() {
+        return Err(GoIamError::InvalidResponse {
+            message: "token is not a valid compact JWS".to_string(),
+        });
+    }
+
+    let decoded = general_purpose::URL_SAFE_NO_PAD
+        .decode(payload)
+        .map_err(|_| GoIamError::InvalidResponse {
+            message: "token payload is not valid base64url".to_string(),
+        })?;
+
+    serde_json::from_slice(&decoded).map_err(|err| GoIamError::InvalidResponse {
+        message: format!("token payload is not valid claims JSON: {err}"),
+    })
+}
+
+#[cfg(feature = "jwt-verify")]
+mod verify {
+    use super::Claims;
+    use crate::error::{GoIamError, Result};
+    use jsonwebtoken::{decode, DecodingKey, Validation};
+
+    /// Decodes and cryptographically verifies the token's signature against
+    /// `key`, checking `exp` and audience per `validation`.
+    pub fn decode_and_verify(
+        token: &str,
+        key: &DecodingKey,
+        validation: &Validation,
+    ) -> Result<Claims> {
+        decode::<Claims>(token, key, validation)
+            .map(|data| data.claims)
+            .map_err(|err| GoIamError::InvalidResponse {
+                message: format!("token verification failed: {err}"),
+            })
+    }
+}
+
+#[cfg(feature = "jwt-verify")]
+pub use verify::decode_and_verify;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_segment(value: &serde_json::Value) -> String {
+        general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(value).unwrap())
+    }
+
+    #[test]
+    fn test_decode_claims_success() {
+        let header = encode_segment(&serde_json::json!({"alg": "HS256", "typ": "JWT"}));
+        let payload = encode_segment(&serde_json::json!({
+            "sub": "user-123",
+            "exp": 9999999999i64,
+            "iat": 1700000000,
+            "project_id": "proj-456",
+            "scope": "read:users"
+        }));
+        let token = format!("{header}.{payload}.signature");
+
+        let claims = decode_claims(&token).expect("expected claims to decode");
+        assert_eq!(claims.sub, "user-123");
+        assert_eq!(claims.project_id, "proj-456");
+        assert_eq!(
+            claims.extra.get("scope").and_then(|v| v.as_str()),
+            Some("read:users")
+        );
+        assert!(!claims.is_expired());
+    }
+
+    #[test]
+    fn test_decode_claims_expired() {
+        let header = encode_segment(&serde_json::json!({"alg": "HS256", "typ": "JWT"}));
+        let payload = encode_segment(&serde_json::json!({
+            "sub": "user-123",
+            "exp": 1,
+            "iat": 0,
+            "project_id": "proj-456"
+        }));
+        let token = format!("{header}.{payload}.signature");
+
+        let claims = decode_claims(&token).expect("expected claims to decode");
+        assert!(claims.is_expired());
+    }
+
+    #[test]
+    fn test_decode_claims_rejects_malformed_token() {
+        let result = decode_claims("not-a-jwt");
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            GoIamError::InvalidResponse { .. } => {}
+            other => panic!("Expected InvalidResponse, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decode_claims_rejects_invalid_base64() {
+        let result = decode_claims("header.not!base64url.signature");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decode_claims_rejects_extra_segment() {
+        let header = encode_segment(&serde_json::json!({"alg": "HS256", "typ": "JWT"}));
+        let payload = encode_segment(&serde_json::json!({
+            "sub": "user-123",
+            "exp": 9999999999i64,
+            "iat": 1700000000,
+            "project_id": "proj-456"
+        }));
+        let token = format!("{header}.{payload}.signature.garbage");
+
+        let result = decode_claims(&token);
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            GoIamError::InvalidResponse { .. } => {}
+            other => panic!("Expected InvalidResponse, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decode_claims_rejects_valid_base64_invalid_json() {
+        let header = encode_segment(&serde_json::json!({"alg": "HS256", "typ": "JWT"}));
+        let payload = general_purpose::URL_SAFE_NO_PAD.encode(b"not valid json, but valid base64url");
+        let token = format!("{header}.{payload}.signature");
+
+        let result = decode_claims(&token);
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            GoIamError::InvalidResponse { .. } => {}
+            other => panic!("Expected InvalidResponse, got {other:?}"),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct User {
@@ -13,6 +13,11 @@ pub struct User {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub linked_client_id: Option<String>,
     pub expiry: Option<String>,
+    /// Space-delimited scopes/claims as returned by `/me/v1/me`. Use
+    /// [`User::has_scope`] / [`User::has_all_scopes`] rather than parsing
+    /// this directly.
+    #[serde(default)]
+    pub scope: String,
     pub roles: HashMap<String, UserRole>,
     pub resources: HashMap<String, UserResource>,
     pub policies: HashMap<String, UserPolicy>,
@@ -22,6 +27,24 @@ pub struct User {
     pub updated_by: String,
 }
 
+impl User {
+    /// Parses [`scope`](Self::scope) into the set of granted scopes.
+    pub fn scopes(&self) -> HashSet<&str> {
+        self.scope.split_whitespace().collect()
+    }
+
+    /// Returns `true` if the user's scopes include `scope`.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes().contains(scope)
+    }
+
+    /// Returns `true` if the user's scopes include every entry in `scopes`.
+    pub fn has_all_scopes(&self, scopes: &[&str]) -> bool {
+        let granted = self.scopes();
+        scopes.iter().all(|scope| granted.contains(scope))
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UserPolicy {
     pub name: String,
@@ -114,6 +137,60 @@ pub struct ResourceResponse {
     pub data: Option<Resource>,
 }
 
+/// A machine-to-machine access token acquired via the client-credentials
+/// grant, returned by [`Service::get_machine_token`](crate::service::Service::get_machine_token).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Token {
+    pub access_token: String,
+    /// Unix timestamp (seconds) at which `access_token` expires.
+    pub expires_at: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MachineTokenData {
+    pub access_token: String,
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MachineTokenResponse {
+    pub success: bool,
+    pub message: Option<String>,
+    pub data: Option<MachineTokenData>,
+}
+
+/// Server-side filters for [`Service::list_resources`](crate::service::Service::list_resources).
+#[derive(Debug, Clone, Default)]
+pub struct ListResourcesQuery {
+    pub key_prefix: Option<String>,
+    pub enabled: Option<bool>,
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+}
+
+/// A page of results with an opaque cursor for fetching the next page.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResourcePageResponse {
+    pub success: bool,
+    pub message: Option<String>,
+    pub data: Option<Page<Resource>>,
+}
+
+/// The JSON body an OAuth2 token endpoint returns on failure, per
+/// [RFC 6749 §5.2](https://www.rfc-editor.org/rfc/rfc6749#section-5.2).
+#[derive(Debug, Deserialize)]
+pub struct OAuthErrorBody {
+    pub error: String,
+    pub error_description: Option<String>,
+    pub error_uri: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,6 +237,7 @@ mod tests {
             profile_pic: "avatar.jpg".to_string(),
             linked_client_id: None,
             expiry: Some("2025-12-31T23:59:59Z".to_string()),
+            scope: "read:users write:users".to_string(),
             roles,
             resources,
             policies,
@@ -181,6 +259,42 @@ mod tests {
         assert_eq!(deserialized.enabled, user.enabled);
     }
 
+    #[test]
+    fn test_user_has_scope() {
+        let user = User {
+            id: "user-123".to_string(),
+            project_id: "proj-456".to_string(),
+            name: "John Doe".to_string(),
+            email: "john@example.com".to_string(),
+            phone: String::new(),
+            enabled: true,
+            profile_pic: String::new(),
+            linked_client_id: None,
+            expiry: None,
+            scope: "read:users write:users".to_string(),
+            roles: HashMap::new(),
+            resources: HashMap::new(),
+            policies: HashMap::new(),
+            created_at: None,
+            created_by: "admin".to_string(),
+            updated_at: None,
+            updated_by: "admin".to_string(),
+        };
+
+        assert!(user.has_scope("read:users"));
+        assert!(!user.has_scope("delete:users"));
+        assert!(user.has_all_scopes(&["read:users", "write:users"]));
+        assert!(!user.has_all_scopes(&["read:users", "delete:users"]));
+    }
+
+    #[test]
+    fn test_user_scope_defaults_when_missing() {
+        let json = r#"{"id":"user-1","project_id":"","name":"Test","email":"test@example.com","phone":"","enabled":true,"profile_pic":"","expiry":null,"roles":{},"resources":{},"policies":{},"created_at":null,"created_by":"","updated_at":null,"updated_by":""}"#;
+        let user: User = serde_json::from_str(json).expect("Failed to deserialize user");
+        assert_eq!(user.scope, "");
+        assert!(!user.has_scope("read:users"));
+    }
+
     #[test]
     fn test_user_policy_mapping_value_static_field() {
         let mapping_value = UserPolicyMappingValue {
@@ -289,6 +403,18 @@ mod tests {
         assert!(response.data.is_none());
     }
 
+    #[test]
+    fn test_resource_page_response_deserialization() {
+        let json = r#"{"success":true,"data":{"items":[{"id":"res-1","name":"Test","description":"","key":"test-key","enabled":true,"project_id":"","created_at":null,"created_by":"","updated_at":null,"updated_by":"","deleted_at":null}],"next_cursor":"abc123"}}"#;
+        let response: ResourcePageResponse =
+            serde_json::from_str(json).expect("Failed to deserialize resource page response");
+
+        let page = response.data.expect("expected a page of resources");
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].id, "res-1");
+        assert_eq!(page.next_cursor.unwrap(), "abc123");
+    }
+
     #[test]
     fn test_user_role_serialization() {
         let role = UserRole {